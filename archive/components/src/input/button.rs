@@ -7,6 +7,43 @@ use std::fmt::Write as _;
 
 const _: &str = manganis::mg!(file("./styles/input/button.css"));
 
+/// The size of a [`Button`], exposed to the stylesheet as `data-size`.
+///
+/// Any [`Size`] converts into the matching button size.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum ButtonSize {
+    Small,
+    Medium,
+    Large,
+    /// A square button that only holds an icon.
+    Icon,
+    #[default]
+    Auto,
+}
+
+impl ButtonSize {
+    pub fn as_data(&self) -> &str {
+        match self {
+            Self::Small => "sm",
+            Self::Medium => "md",
+            Self::Large => "lg",
+            Self::Icon => "icon",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+impl From<Size> for ButtonSize {
+    fn from(size: Size) -> Self {
+        match size {
+            Size::Small => Self::Small,
+            Size::Medium => Self::Medium,
+            Size::Large => Self::Large,
+            Size::Auto => Self::Auto,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 pub(crate) struct ButtonSpacing(pub(crate) Signal<u8>);
 
@@ -59,10 +96,10 @@ impl AsCss for ButtonStyling {
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ButtonProps {
-    /// Optional size for this  button.
-    /// See [`style::Size`] for more info.
-    #[props(optional)]
-    size: Size,
+    /// Optional size for this button.
+    /// See [`ButtonSize`] for more info.
+    #[props(optional, into)]
+    size: ButtonSize,
 
     /// Optional styling for this button.
     #[props(optional)]
@@ -77,6 +114,20 @@ pub struct ButtonProps {
     #[props(optional, default = false)]
     disabled: bool,
 
+    /// Optionally mark this button as loading.
+    /// A loading button shows a spinner over its label and ignores clicks without changing width.
+    /// Defaults to `false`.
+    #[props(optional, default = false)]
+    loading: bool,
+
+    /// Optional icon displayed before the label.
+    #[props(optional)]
+    leading_icon: Element,
+
+    /// Optional icon displayed after the label.
+    #[props(optional)]
+    trailing_icon: Element,
+
     /// Optionally listen to click events from this button.
     #[props(optional)]
     on_click: EventHandler<MouseEvent>,
@@ -92,6 +143,8 @@ pub fn Button(props: ButtonProps) -> Element {
     };
 
     let disabled_class = if props.disabled { "disabled" } else { "" };
+    let loading_class = if props.loading { "loading" } else { "" };
+    let is_inactive = props.disabled || props.loading;
 
     // Handles color transition on mouse hover.
     let mut mouse_hover = use_signal(|| false);
@@ -136,14 +189,18 @@ pub fn Button(props: ButtonProps) -> Element {
 
     rsx! {
         button {
-            class: "dxc-button {props.size.as_class()} {disabled_class} {independent_class}",
+            class: "dxc-button {disabled_class} {loading_class} {independent_class}",
+            "data-size": props.size.as_data(),
 
             style: "{styling.as_css()}",
-            style: if !props.disabled && mouse_hover() { "{styling.hover_background_color.as_bg_css()}" },
+            style: if !is_inactive && mouse_hover() { "{styling.hover_background_color.as_bg_css()}" },
             style: "{btn_spacing_css}",
 
+            aria_busy: props.loading.then_some("true"),
+            aria_disabled: props.loading.then_some("true"),
+
             onclick: move |evt| {
-                if !props.disabled {
+                if !is_inactive {
                     props.on_click.call(evt);
                 }
             },
@@ -154,7 +211,33 @@ pub fn Button(props: ButtonProps) -> Element {
                 mouse_hover.set(false);
             },
 
-            {props.children}
+            // The content stays rendered while loading so the button keeps its width
+            // and the label remains available to screen readers.
+            span {
+                class: "dxc-button-content",
+                if props.leading_icon.is_some() {
+                    span {
+                        class: "dxc-button-icon",
+                        aria_hidden: "true",
+                        {props.leading_icon}
+                    }
+                }
+                {props.children}
+                if props.trailing_icon.is_some() {
+                    span {
+                        class: "dxc-button-icon",
+                        aria_hidden: "true",
+                        {props.trailing_icon}
+                    }
+                }
+            }
+
+            if props.loading {
+                span {
+                    class: "dxc-button-spinner",
+                    aria_hidden: "true",
+                }
+            }
         }
     }
 }
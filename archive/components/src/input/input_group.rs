@@ -0,0 +1,66 @@
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+const _: &str = manganis::mg!(file("./styles/input/input_group.css"));
+
+/// The mounted [`Input`](crate::input::Input) of an [`InputGroup`], focused when an addon is clicked.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct InputGroupInput(pub(crate) Signal<Option<Rc<MountedData>>>);
+
+impl InputGroupInput {
+    fn focus(&self) {
+        if let Some(input) = self.0() {
+            spawn(async move {
+                input.set_focus(true).await.ok();
+            });
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct InputGroupProps {
+    children: Element,
+}
+
+/// Attaches [`InputLeadingAddon`]s and [`InputTrailingAddon`]s to an [`Input`](crate::input::Input).
+pub fn InputGroup(props: InputGroupProps) -> Element {
+    use_context_provider(|| InputGroupInput(Signal::new(None)));
+
+    rsx! {
+        div {
+            class: "dxc-input-group",
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct InputAddonProps {
+    children: Element,
+}
+
+/// Content displayed before the input of an [`InputGroup`], such as an icon or `https://`.
+pub fn InputLeadingAddon(props: InputAddonProps) -> Element {
+    addon("leading", props.children)
+}
+
+/// Content displayed after the input of an [`InputGroup`], such as a unit label.
+pub fn InputTrailingAddon(props: InputAddonProps) -> Element {
+    addon("trailing", props.children)
+}
+
+fn addon(position: &str, children: Element) -> Element {
+    let group_input = try_consume_context::<InputGroupInput>();
+
+    rsx! {
+        span {
+            class: "dxc-input-addon {position}",
+            onclick: move |_| {
+                if let Some(group_input) = group_input {
+                    group_input.focus();
+                }
+            },
+            {children}
+        }
+    }
+}
@@ -0,0 +1,268 @@
+/// A single position within a [`Mask`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum MaskSlot {
+    /// `#` - accepts an ASCII digit.
+    Digit,
+    /// `A` - accepts an alphabetic character.
+    Letter,
+    /// `*` - accepts any alphanumeric character.
+    Alphanumeric,
+    /// Any other character, inserted automatically.
+    Literal(char),
+}
+
+impl MaskSlot {
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+/// The direction of a deletion made by the user.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Deletion {
+    /// Backspace, deleting before the caret.
+    Backward,
+    /// Delete, deleting after the caret.
+    Forward,
+}
+
+/// The result of formatting some input against a [`Mask`].
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct MaskEdit {
+    /// The formatted value, including literal characters.
+    pub(crate) value: String,
+    /// The value without literal characters.
+    pub(crate) raw: String,
+    /// The caret position in `value`, in characters.
+    pub(crate) caret: usize,
+}
+
+/// An input mask such as `"(###) ###-####"`.
+///
+/// Pattern syntax:
+/// - `#` accepts a digit.
+/// - `A` accepts a letter.
+/// - `*` accepts a letter or digit.
+/// - Any other character is a literal that is inserted automatically.
+///
+/// Characters that don't fit the next fillable slot are rejected.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Mask {
+    slots: Vec<MaskSlot>,
+}
+
+impl Mask {
+    pub fn new(pattern: &str) -> Self {
+        let slots = pattern
+            .chars()
+            .map(|c| match c {
+                '#' => MaskSlot::Digit,
+                'A' => MaskSlot::Letter,
+                '*' => MaskSlot::Alphanumeric,
+                c => MaskSlot::Literal(c),
+            })
+            .collect();
+
+        Self { slots }
+    }
+
+    /// Format `input` against this mask.
+    pub fn format(&self, input: &str) -> String {
+        self.fill(input, 0).0.value
+    }
+
+    /// Strip the literal characters from `input`, returning only what the user entered.
+    pub fn strip(&self, input: &str) -> String {
+        self.fill(input, 0).0.raw
+    }
+
+    /// Apply an edit made by the user.
+    ///
+    /// `previous` is the value before the edit, `edited` is the value the browser produced,
+    /// and `caret` is the caret position within `edited`. If a deletion only removed literal
+    /// characters, the nearest fillable character in the direction of the deletion is removed
+    /// instead so that the caret doesn't get stuck on the literal.
+    pub(crate) fn edit(
+        &self,
+        previous: &str,
+        edited: &str,
+        caret: usize,
+        deletion: Option<Deletion>,
+    ) -> MaskEdit {
+        let (edit, raw_before_caret) = self.fill(edited, caret);
+
+        let removed_only_literals =
+            edited.chars().count() < previous.chars().count() && edit.raw == self.strip(previous);
+
+        let (raw, raw_caret) = match (deletion, removed_only_literals) {
+            (Some(Deletion::Backward), true) if raw_before_caret > 0 => (
+                remove_char(&edit.raw, raw_before_caret - 1),
+                raw_before_caret - 1,
+            ),
+            (Some(Deletion::Forward), true) if raw_before_caret < edit.raw.chars().count() => {
+                (remove_char(&edit.raw, raw_before_caret), raw_before_caret)
+            }
+            _ => return edit,
+        };
+
+        self.fill(&raw, raw_caret).0
+    }
+
+    /// Fill the mask's slots from `input`.
+    ///
+    /// Returns the edit along with the number of accepted characters that were before `caret`.
+    fn fill(&self, input: &str, caret: usize) -> (MaskEdit, usize) {
+        let mut value = String::new();
+        let mut raw = String::new();
+        let mut out_caret = 0;
+        let mut raw_before_caret = 0;
+        // Byte length of `value` after the last filled slot, used to trim trailing literals.
+        let mut filled_len = 0;
+
+        let mut chars = input.chars().enumerate().peekable();
+
+        for slot in &self.slots {
+            if chars.peek().is_none() {
+                break;
+            }
+
+            if let MaskSlot::Literal(literal) = slot {
+                // Consume the literal if the input already contains it.
+                chars.next_if(|(_, c)| c == literal);
+                value.push(*literal);
+                continue;
+            }
+
+            let Some((index, c)) = chars.by_ref().find(|(_, c)| slot.accepts(*c)) else {
+                break;
+            };
+
+            value.push(c);
+            raw.push(c);
+            filled_len = value.len();
+
+            if index < caret {
+                out_caret = value.chars().count();
+                raw_before_caret += 1;
+            }
+        }
+
+        value.truncate(filled_len);
+
+        let edit = MaskEdit {
+            value,
+            raw,
+            caret: out_caret,
+        };
+
+        (edit, raw_before_caret)
+    }
+}
+
+/// Convert an offset in UTF-16 code units, as used by the DOM's selection APIs, to a character
+/// index into `value`.
+pub(crate) fn utf16_to_char_index(value: &str, offset: usize) -> usize {
+    let mut units = 0;
+    value
+        .chars()
+        .take_while(|c| {
+            units += c.len_utf16();
+            units <= offset
+        })
+        .count()
+}
+
+/// Convert a character index into `value` to an offset in UTF-16 code units.
+pub(crate) fn char_index_to_utf16(value: &str, index: usize) -> usize {
+    value.chars().take(index).map(char::len_utf16).sum()
+}
+
+fn remove_char(value: &str, index: usize) -> String {
+    value
+        .chars()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, c)| c)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phone() -> Mask {
+        Mask::new("(###) ###-####")
+    }
+
+    #[test]
+    fn typing_inserts_literals() {
+        let edit = phone().edit("", "5", 1, None);
+        assert_eq!(edit.value, "(5");
+        assert_eq!(edit.raw, "5");
+        assert_eq!(edit.caret, 2);
+
+        let edit = phone().edit("(555", "(5551", 5, None);
+        assert_eq!(edit.value, "(555) 1");
+        assert_eq!(edit.raw, "5551");
+        assert_eq!(edit.caret, 7);
+    }
+
+    #[test]
+    fn insert_mid_string_keeps_caret_after_insertion() {
+        let edit = phone().edit("(555) 123", "(5595) 123", 4, None);
+        assert_eq!(edit.value, "(559) 512-3");
+        assert_eq!(edit.raw, "5595123");
+        assert_eq!(edit.caret, 4);
+    }
+
+    #[test]
+    fn paste_is_formatted() {
+        let edit = phone().edit("", "5551234567", 10, None);
+        assert_eq!(edit.value, "(555) 123-4567");
+        assert_eq!(edit.raw, "5551234567");
+        assert_eq!(edit.caret, 14);
+
+        assert_eq!(
+            Mask::new("#### #### #### ####").format("4111111111111111"),
+            "4111 1111 1111 1111"
+        );
+    }
+
+    #[test]
+    fn rejects_characters_that_do_not_fit() {
+        let edit = phone().edit("(555", "(555x", 5, None);
+        assert_eq!(edit.value, "(555");
+        assert_eq!(edit.raw, "555");
+        assert_eq!(edit.caret, 4);
+    }
+
+    #[test]
+    fn backspace_over_literals_removes_previous_fillable() {
+        let edit = phone().edit("(555) 1", "(555)1", 5, Some(Deletion::Backward));
+        assert_eq!(edit.value, "(551");
+        assert_eq!(edit.raw, "551");
+        assert_eq!(edit.caret, 3);
+    }
+
+    #[test]
+    fn delete_over_literals_removes_next_fillable() {
+        let edit = phone().edit("(555) 1", "(555 1", 4, Some(Deletion::Forward));
+        assert_eq!(edit.value, "(555");
+        assert_eq!(edit.raw, "555");
+        assert_eq!(edit.caret, 4);
+    }
+
+    #[test]
+    fn converts_utf16_offsets() {
+        let value = "a\u{1F600}b";
+        assert_eq!(utf16_to_char_index(value, 1), 1);
+        assert_eq!(utf16_to_char_index(value, 3), 2);
+        assert_eq!(char_index_to_utf16(value, 2), 3);
+        assert_eq!(char_index_to_utf16(value, 3), 4);
+    }
+}
@@ -3,3 +3,12 @@ pub use button::*;
 
 mod button_group;
 pub use button_group::*;
+
+mod mask;
+pub use mask::*;
+
+mod text_input;
+pub use text_input::*;
+
+mod input_group;
+pub use input_group::*;
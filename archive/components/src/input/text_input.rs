@@ -0,0 +1,145 @@
+use crate::{
+    input::{char_index_to_utf16, utf16_to_char_index, Deletion, InputGroupInput, Mask},
+    style::Size,
+};
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+const _: &str = manganis::mg!(file("./styles/input/input.css"));
+
+#[derive(Props, Clone, PartialEq)]
+pub struct InputProps {
+    /// Optional size for this input.
+    /// See [`style::Size`] for more info.
+    #[props(optional)]
+    size: Size,
+
+    /// Optional text shown while the input is empty.
+    placeholder: Option<String>,
+
+    /// Optional mask that formats the value as the user types, e.g. `"(###) ###-####"`.
+    /// See [`Mask`] for the pattern syntax.
+    mask: Option<String>,
+
+    /// Optionally mark this input as disabled.
+    /// Defaults to `false`.
+    #[props(optional, default = false)]
+    disabled: bool,
+
+    /// Optionally show a button that clears the input while it has a value.
+    /// Defaults to `false`.
+    #[props(optional, default = false)]
+    clearable: bool,
+
+    /// Optionally listen to input events from this input.
+    /// If a mask is set, the value is reported without the mask's literal characters.
+    #[props(optional)]
+    on_input: EventHandler<String>,
+}
+
+pub fn Input(props: InputProps) -> Element {
+    let mut value = use_signal(String::new);
+    let mut deletion = use_signal(|| None::<Deletion>);
+    let mut mounted = use_signal(|| None::<Rc<MountedData>>);
+    let group_input = try_consume_context::<InputGroupInput>();
+
+    let mask = props.mask.as_deref().map(Mask::new);
+    let disabled_class = if props.disabled { "disabled" } else { "" };
+
+    // Remember which way the user is deleting so deletions over literals can skip past them.
+    let on_key_down = move |evt: KeyboardEvent| {
+        let direction = match evt.key() {
+            Key::Backspace => Some(Deletion::Backward),
+            Key::Delete => Some(Deletion::Forward),
+            _ => None,
+        };
+        deletion.set(direction);
+    };
+
+    let on_input = move |evt: FormEvent| {
+        let edited = evt.value();
+
+        let Some(mask) = mask.clone() else {
+            value.set(edited.clone());
+            props.on_input.call(edited);
+            return;
+        };
+
+        spawn(async move {
+            // The caret has to be read from and written to the DOM. The value is written here
+            // as well, since a rejected character wouldn't change `value` and so wouldn't be
+            // removed by a re-render.
+            let mut eval = eval(
+                r#"
+                const input = document.activeElement;
+                dioxus.send(input.selectionStart ?? input.value.length);
+                const value = await dioxus.recv();
+                const caret = await dioxus.recv();
+                input.value = value;
+                input.setSelectionRange(caret, caret);
+                "#,
+            );
+
+            // The DOM counts the caret in UTF-16 code units, the mask in characters.
+            let caret = match eval.recv().await {
+                Ok(caret) => {
+                    utf16_to_char_index(&edited, caret.as_u64().unwrap_or_default() as usize)
+                }
+                Err(_) => edited.chars().count(),
+            };
+
+            let direction = deletion();
+            deletion.set(None);
+
+            let edit = mask.edit(&value.peek(), &edited, caret, direction);
+            value.set(edit.value.clone());
+
+            let caret = char_index_to_utf16(&edit.value, edit.caret);
+            eval.send(edit.value.into()).ok();
+            eval.send((caret as u64).into()).ok();
+
+            props.on_input.call(edit.raw);
+        });
+    };
+
+    let on_clear = move |_| {
+        value.set(String::new());
+        props.on_input.call(String::new());
+
+        if let Some(input) = mounted() {
+            spawn(async move {
+                input.set_focus(true).await.ok();
+            });
+        }
+    };
+
+    rsx! {
+        input {
+            class: "dxc-input {props.size.as_class()} {disabled_class}",
+            value: "{value}",
+            placeholder: props.placeholder,
+            disabled: props.disabled,
+
+            onmounted: move |evt: MountedEvent| {
+                mounted.set(Some(evt.data()));
+
+                // Let the group's addons focus this input.
+                if let Some(mut group_input) = group_input {
+                    group_input.0.set(Some(evt.data()));
+                }
+            },
+            onkeydown: on_key_down,
+            oninput: on_input,
+        }
+
+        if props.clearable && !props.disabled && !value().is_empty() {
+            button {
+                class: "dxc-input-clear",
+                r#type: "button",
+                aria_label: "Clear",
+                onclick: on_clear,
+                "×"
+            }
+        }
+    }
+}
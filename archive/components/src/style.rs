@@ -5,11 +5,12 @@ pub trait AsCss {
     fn as_css(&self) -> String;
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum Size {
     Small,
     Medium,
     Large,
+    #[default]
     Auto,
 }
 
@@ -24,12 +25,6 @@ impl Size {
     }
 }
 
-impl Default for Size {
-    fn default() -> Self {
-        Self::Auto
-    }
-}
-
 // We have a separate color enum so that we can enforce
 // usage of type-checking functions.
 #[derive(Clone, PartialEq)]
@@ -88,8 +83,9 @@ impl Display for Color {
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum FontFamily {
+    #[default]
     Arial,
 }
 
@@ -105,14 +101,9 @@ impl AsCss for FontFamily {
     }
 }
 
-impl Default for FontFamily {
-    fn default() -> Self {
-        Self::Arial
-    }
-}
-
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum Orientation {
+    #[default]
     Horizontal,
     Vertical,
 }
@@ -132,9 +123,3 @@ impl Orientation {
         }
     }
 }
-
-impl Default for Orientation {
-    fn default() -> Self {
-        Self::Horizontal
-    }
-}
@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 
-use crate::use_aria_id;
+use crate::{hide_until_found, use_aria_id, OpenState};
 
 #[derive(Props, Clone, PartialEq)]
 pub struct AccordionProps {
@@ -10,31 +10,161 @@ pub struct AccordionProps {
     label: String,
     expanded: Signal<bool>,
 
+    /// An indicator rendered inside the trigger after the label, such as a chevron.
+    ///
+    /// Components within it can read the open state with [`use_open_state`](crate::use_open_state).
+    indicator: Option<Element>,
+
+    /// Keep collapsed content mounted so that it can be revealed when printing.
+    ///
+    /// Collapsed content is rendered with `hidden` and `inert` so it stays out of the
+    /// accessibility tree on screen, and is stamped with `data-print-expand` so print
+    /// stylesheets can reveal it under `@media print`.
+    ///
+    /// Defaults to `true`. When `false`, collapsed content stays mounted only as allowed by
+    /// `keep_mounted`. Lazy content is not constructed for print until first expanded.
+    #[props(optional, default = true)]
+    force_mount_for_print: bool,
+
+    /// Construct the content only once the accordion is first expanded.
+    /// Defaults to `false`.
+    #[props(optional, default = false)]
+    lazy: bool,
+
+    /// Keep the content mounted when collapsing after it has been expanded once.
+    /// Defaults to `true`. When `false`, collapsed content is unmounted unless
+    /// `force_mount_for_print` is set.
+    #[props(optional, default = true)]
+    keep_mounted: bool,
+
+    /// Render collapsed content with `hidden="until-found"` instead of `hidden` and `inert`, so
+    /// the browser's find-in-page can search it. Finding a match expands the accordion.
+    ///
+    /// This has no effect on collapsed content that isn't mounted, such as `lazy` content that
+    /// was never expanded, since there is nothing in the page to search.
+    ///
+    /// Defaults to `false`.
+    #[props(optional, default = false)]
+    reveal_on_find: bool,
+
+    /// Event that is fired when the root element wrapping the header and content is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    /// Event that is fired when the trigger button is mounted.
+    #[props(optional)]
+    on_trigger_mounted: EventHandler<MountedEvent>,
+
+    /// Event that is fired when the content region is mounted.
+    #[props(optional)]
+    on_content_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    /// Attributes applied to the heading that wraps the trigger button.
+    #[props(optional)]
+    wrapper_attributes: Vec<Attribute>,
+
     children: Element,
 }
 
+/// The `Accordion` ARIA pattern.
+///
+/// See the [accordion pattern](https://www.w3.org/WAI/ARIA/apg/patterns/accordion/).
 #[component]
 pub fn Accordion(props: AccordionProps) -> Element {
     let aria_label_id = use_aria_id();
     let aria_controls_id = use_aria_id();
 
+    let mut expanded = props.expanded;
+    let is_expanded = expanded();
+
+    use_context_provider(|| OpenState(expanded.into()));
+
+    let mut has_expanded = use_signal(|| is_expanded);
+    if is_expanded && !*has_expanded.peek() {
+        has_expanded.set(true);
+    }
+
+    let keep_collapsed = props.force_mount_for_print || (props.keep_mounted && has_expanded());
+    let is_mounted = is_expanded || (keep_collapsed && (!props.lazy || has_expanded()));
+    let reveal_on_find = props.reveal_on_find;
+
+    // `inert` would also exclude the content from find-in-page, so searchable content relies on
+    // `hidden="until-found"` alone.
+    let inert_val = match is_expanded || reveal_on_find {
+        true => None,
+        false => Some("true"),
+    };
+
+    let match_id = aria_controls_id.clone();
+    let controls_val = is_mounted.then(|| aria_controls_id.clone());
+    let mut find_task = use_signal(|| None::<Task>);
+
+    let state_val = match is_expanded {
+        true => "open",
+        false => "closed",
+    };
+
+    let print_expand_val = match props.force_mount_for_print {
+        true => Some("true"),
+        false => None,
+    };
+
     rsx! {
         div {
             class: "{props.class}",
+            onmounted: move |data| props.on_mounted.call(data),
+            "data-part": "root",
+            ..props.attributes,
             h3 {
+                "data-part": "header",
+                ..props.wrapper_attributes,
                 button {
                     id: "{aria_label_id}",
-                    aria_expanded: "false",
-                    aria_controls: "{aria_controls_id}",
+                    aria_expanded: "{is_expanded}",
+                    aria_controls: controls_val,
+                    onclick: move |_| expanded.toggle(),
+                    onmounted: move |data| props.on_trigger_mounted.call(data),
+                    "data-part": "trigger",
+                    "data-state": state_val,
                     "{props.label}"
+                    if let Some(indicator) = props.indicator {
+                        span {
+                            aria_hidden: "true",
+                            "data-part": "indicator",
+                            {indicator}
+                        }
+                    }
                 }
             }
-            div {
-                id: "{aria_controls_id}",
-                aria_labelledby: "{aria_label_id}",
-                role: "region",
+            if is_mounted {
+                div {
+                    id: "{aria_controls_id}",
+                    aria_labelledby: "{aria_label_id}",
+                    role: "region",
+                    hidden: !is_expanded,
+                    "inert": inert_val,
+                    "data-print-expand": print_expand_val,
+                    "data-part": "content",
+                    onmounted: move |data| {
+                        // Content that is mounted again replaces the element the previous task
+                        // was watching.
+                        if let Some(task) = find_task.write().take() {
+                            remove_future(task);
+                        }
+                        if reveal_on_find {
+                            let task = spawn(hide_until_found(match_id.clone(), move || {
+                                expanded.set(true)
+                            }));
+                            find_task.set(Some(task));
+                        }
+                        props.on_content_mounted.call(data);
+                    },
 
-                {props.children}
+                    {props.children}
+                }
             }
         }
     }
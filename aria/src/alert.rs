@@ -1,27 +1,292 @@
 use dioxus::prelude::*;
 
+use crate::{use_aria_id, use_reduced_motion};
+
+/// The kind of message an [`Alert`] conveys.
+///
+/// `Info` and `Success` are not urgent and use `role="status"`, which waits for the screen
+/// reader to finish its current speech. `Warning` and `Error` use `role="alert"`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AlertVariant {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl AlertVariant {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+
+    fn role(&self) -> &'static str {
+        match self {
+            Self::Info | Self::Success => "status",
+            Self::Warning | Self::Error => "alert",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct AlertContext {
+    id: Signal<String>,
+    closing: Signal<bool>,
+    skip_animation: Signal<bool>,
+    on_dismiss: Signal<EventHandler<()>>,
+}
+
+impl AlertContext {
+    fn title_id(&self) -> String {
+        format!("{}-title", self.id.peek())
+    }
+
+    fn description_id(&self) -> String {
+        format!("{}-description", self.id.peek())
+    }
+
+    /// Call `on_dismiss` once any exit animations have finished.
+    fn dismiss(mut self) {
+        if *self.closing.peek() {
+            return;
+        }
+        self.closing.set(true);
+
+        if *self.skip_animation.peek() {
+            self.closing.set(false);
+            self.on_dismiss.peek().call(());
+            return;
+        }
+
+        let id = self.id.peek().clone();
+        spawn(async move {
+            let mut eval = eval(
+                r#"
+                const id = await dioxus.recv();
+                await new Promise((resolve) => requestAnimationFrame(resolve));
+                const element = document.getElementById(id);
+                if (element) {
+                    await Promise.allSettled(element.getAnimations({ subtree: true }).map((a) => a.finished));
+                }
+                dioxus.send(true);
+                "#,
+            );
+            eval.send(id.into()).ok();
+            eval.recv().await.ok();
+
+            self.closing.set(false);
+            self.on_dismiss.peek().call(());
+        });
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct AlertProps {
     #[props(optional, default = "dxa-alert".into())]
     class: String,
+
+    /// The kind of message, exposed as `data-variant`.
+    ///
+    /// When not set, the alert always uses `role="alert"`.
+    variant: Option<AlertVariant>,
+
+    /// Event that is fired once the alert has been dismissed with an [`AlertDismiss`] and its
+    /// exit animation has finished. The parent should stop rendering the alert in response.
+    #[props(optional)]
+    on_dismiss: EventHandler<()>,
+
+    /// Remove the alert immediately on dismissal when the user prefers reduced motion, instead
+    /// of waiting for exit animations.
+    /// Defaults to `true`.
+    #[props(optional, default = true)]
+    respect_reduced_motion: bool,
+
+    /// Event that is fired when the alert element is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
     children: Element,
 }
 
 /// The `Alert` ARIA pattern.
-/// 
+///
 /// When rendered or content is changed, screen readers can interrupt any current text-to-speech
 /// with the new alert content. Items within an alert should not interrupt the current keyboard focus.
-/// 
-/// Alerts should be used infrequently and should not disappear automatically.
-/// 
+///
+/// The alert is labelled by its [`AlertTitle`] and described by its [`AlertDescription`], so
+/// both should be rendered.
+///
+/// Alerts should be used infrequently and should not disappear automatically. When the user
+/// dismisses an alert through its [`AlertDismiss`], `data-state` changes to `closed` and
+/// `on_dismiss` fires once any exit animation finishes. The parent owns the alert's visibility
+/// and removes it from `on_dismiss`:
+///
+/// ```rust, ignore
+/// let mut show = use_signal(|| true);
+///
+/// rsx! {
+///     if show() {
+///         Alert {
+///             on_dismiss: move |_| show.set(false),
+///             AlertTitle { "Saved" }
+///             AlertDismiss { "×" }
+///         }
+///     }
+/// }
+/// ```
+///
 /// See the [alert pattern](https://www.w3.org/WAI/ARIA/apg/patterns/alert/).
 #[component]
 pub fn Alert(props: AlertProps) -> Element {
+    let aria_id = use_aria_id();
+    let reduced_motion = use_reduced_motion();
+    let skip_animation = props.respect_reduced_motion && reduced_motion();
+
+    let mut ctx = use_context_provider(|| AlertContext {
+        id: Signal::new(aria_id),
+        closing: Signal::new(false),
+        skip_animation: Signal::new(skip_animation),
+        on_dismiss: Signal::new(props.on_dismiss),
+    });
+
+    if *ctx.on_dismiss.peek() != props.on_dismiss {
+        ctx.on_dismiss.set(props.on_dismiss);
+    }
+
+    if *ctx.skip_animation.peek() != skip_animation {
+        ctx.skip_animation.set(skip_animation);
+    }
+
+    let role = props.variant.map(|v| v.role()).unwrap_or("alert");
+    let variant_val = props.variant.map(|v| v.as_str());
+
+    let state_val = match (ctx.closing)() {
+        true => "closed",
+        false => "open",
+    };
+
+    rsx! {
+        div {
+            class: "{props.class}",
+            id: "{ctx.id}",
+            role,
+            aria_labelledby: "{ctx.title_id()}",
+            aria_describedby: "{ctx.description_id()}",
+            onmounted: move |data| props.on_mounted.call(data),
+
+            "data-variant": variant_val,
+            "data-state": state_val,
+            "data-part": "root",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AlertTitleProps {
+    #[props(optional, default = "dxa-alert-title".into())]
+    class: String,
+
+    /// Event that is fired when the title element is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// The title of an [`Alert`], used as its accessible name.
+#[component]
+pub fn AlertTitle(props: AlertTitleProps) -> Element {
+    let id = use_context::<AlertContext>().title_id();
+
     rsx! {
         div {
             class: "{props.class}",
-            role: "alert",
+            id: "{id}",
+            onmounted: move |data| props.on_mounted.call(data),
+            "data-part": "title",
+            ..props.attributes,
             {props.children}
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AlertDescriptionProps {
+    #[props(optional, default = "dxa-alert-description".into())]
+    class: String,
+
+    /// Event that is fired when the description element is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// The description of an [`Alert`], used as its accessible description.
+#[component]
+pub fn AlertDescription(props: AlertDescriptionProps) -> Element {
+    let id = use_context::<AlertContext>().description_id();
+
+    rsx! {
+        div {
+            class: "{props.class}",
+            id: "{id}",
+            onmounted: move |data| props.on_mounted.call(data),
+            "data-part": "description",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AlertDismissProps {
+    #[props(optional, default = "dxa-alert-dismiss".into())]
+    class: String,
+
+    /// The accessible label of the button.
+    #[props(optional, default = "Dismiss".into())]
+    label: String,
+
+    /// Event that is fired when the dismiss button is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// A button that dismisses its [`Alert`].
+#[component]
+pub fn AlertDismiss(props: AlertDismissProps) -> Element {
+    let ctx = use_context::<AlertContext>();
+
+    rsx! {
+        button {
+            class: "{props.class}",
+            aria_label: "{props.label}",
+            onclick: move |_| ctx.dismiss(),
+            onmounted: move |data| props.on_mounted.call(data),
+            "data-part": "dismiss",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
@@ -1,6 +1,89 @@
-use crate::Icon;
+use crate::{input_modality, use_track_input_modality, Icon, ToggleChange};
 use dioxus::prelude::*;
 
+/// The visible content of a button-like ARIA pattern.
+///
+/// Label-only and icon+label content are labelled by their visible text. Icon-only content
+/// has no visible text, so it can't be built without an accessible label:
+///
+/// ```
+/// # use dioxus_aria::{ButtonContent, Icon};
+/// # let icon = Icon { src: "mute.png".into(), width: 24, height: 24 };
+/// let content = ButtonContent::Icon { icon, aria_label: "Mute".into() };
+/// ```
+///
+/// ```compile_fail
+/// # use dioxus_aria::{ButtonContent, Icon};
+/// # let icon = Icon { src: "mute.png".into(), width: 24, height: 24 };
+/// let content = ButtonContent::Icon { icon };
+/// ```
+#[derive(Clone, PartialEq)]
+pub enum ButtonContent {
+    /// A visible text label.
+    Label(String),
+    /// An icon with no visible text, announced using `aria_label`.
+    Icon { icon: Icon, aria_label: String },
+    /// An icon followed by a visible text label.
+    IconLabel { icon: Icon, label: String },
+}
+
+impl ButtonContent {
+    /// The label that is visible on screen, if any.
+    pub(crate) fn visible_label(&self) -> Option<&str> {
+        match self {
+            Self::Label(label) | Self::IconLabel { label, .. } => Some(label),
+            Self::Icon { .. } => None,
+        }
+    }
+
+    /// The `aria-label` needed when there is no visible label.
+    pub(crate) fn aria_label(&self) -> Option<String> {
+        match self {
+            Self::Icon { aria_label, .. } => Some(aria_label.clone()),
+            _ => None,
+        }
+    }
+
+    /// Render the content, giving any visible label the provided id.
+    pub(crate) fn render(&self, label_id: &str) -> Element {
+        let icon = match self {
+            Self::Icon { icon, .. } | Self::IconLabel { icon, .. } => Some(icon.clone()),
+            Self::Label(_) => None,
+        };
+
+        rsx! {
+            if let Some(icon) = icon {
+                img {
+                    src: icon.src,
+                    width: "{icon.width}",
+                    height: "{icon.height}",
+                    alt: "",
+                    "data-part": "icon",
+                }
+            }
+            if let Some(label) = self.visible_label() {
+                span {
+                    id: "{label_id}",
+                    "data-part": "label",
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+impl From<&str> for ButtonContent {
+    fn from(value: &str) -> Self {
+        Self::Label(value.to_string())
+    }
+}
+
+impl From<String> for ButtonContent {
+    fn from(value: String) -> Self {
+        Self::Label(value)
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ButtonProps {
     #[props(optional, default = "dxa-button".into())]
@@ -20,10 +103,22 @@ pub struct ButtonProps {
     #[props(optional)]
     on_focus: EventHandler<FocusEvent>,
 
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
     /// Event that is fired whenever the toggled state of the button changes.
     ///
     /// Supplying this event handler will convert this button to a toggle button.
     on_toggled: Option<EventHandler<bool>>,
+
+    /// Event that is fired alongside `on_toggled`, with the previous state and the kind of
+    /// input that caused the change.
+    ///
+    /// Supplying this event handler also converts this button to a toggle button.
+    on_change: Option<EventHandler<ToggleChange>>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 /// The `Button` ARIA pattern.
@@ -32,12 +127,24 @@ pub struct ButtonProps {
 #[component]
 pub fn Button(props: ButtonProps) -> Element {
     let mut is_toggled = use_signal(|| false);
+    let is_toggle = props.on_toggled.is_some() || props.on_change.is_some();
+    use_track_input_modality();
 
     let on_click = move |data| {
-        if let Some(toggled_e) = props.on_toggled {
-            is_toggled.toggle();
-            let value = is_toggled();
-            toggled_e.call(value);
+        if is_toggle {
+            let previous = is_toggled();
+            is_toggled.set(!previous);
+
+            if let Some(toggled_e) = props.on_toggled {
+                toggled_e.call(!previous);
+            }
+            if let Some(change_e) = props.on_change {
+                change_e.call(ToggleChange {
+                    value: !previous,
+                    previous,
+                    source: input_modality(),
+                });
+            }
         }
 
         props.on_click.call(data);
@@ -46,8 +153,9 @@ pub fn Button(props: ButtonProps) -> Element {
     let on_mouse_enter = move |data| props.on_mouse_enter.call(data);
     let on_mouse_leave = move |data| props.on_mouse_leave.call(data);
     let on_focus = move |data| props.on_focus.call(data);
+    let on_mounted = move |data| props.on_mounted.call(data);
 
-    let aria_pressed_val = match props.on_toggled.is_some() {
+    let aria_pressed_val = match is_toggle {
         true => match is_toggled() {
             true => Some("true"),
             false => Some("false"),
@@ -55,7 +163,7 @@ pub fn Button(props: ButtonProps) -> Element {
         false => None,
     };
 
-    let toggled_val = match props.on_toggled.is_some() {
+    let toggled_val = match is_toggle {
         true => match is_toggled() {
             true => Some("true"),
             false => Some("false"),
@@ -76,16 +184,20 @@ pub fn Button(props: ButtonProps) -> Element {
             onmouseenter: on_mouse_enter,
             onmouseleave: on_mouse_leave,
             onfocus: on_focus,
+            onmounted: on_mounted,
             // Aria
             aria_pressed: aria_pressed_val,
             aria_label: aria_label_val,
 
             "toggled": toggled_val,
+            "data-part": "root",
+            ..props.attributes,
             if let Some(icon) = props.icon {
                 img {
                     src: icon.src,
                     width: "{icon.width}",
                     height: "{icon.height}",
+                    "data-part": "icon",
                  }
              } else {
                 "{props.label}"
@@ -96,6 +208,6 @@ pub fn Button(props: ButtonProps) -> Element {
 
 //
 //
-//  Multiple button variants for all ARIA use-cases? e.g. TextButton, IconButton, ToggleButton
+//  Multiple button variants for all ARIA use-cases? e.g. TextButton, IconButton
 //
 //
@@ -0,0 +1,333 @@
+use dioxus::prelude::*;
+
+use crate::use_aria_id;
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct EmptyStateContext {
+    id: Signal<String>,
+}
+
+impl EmptyStateContext {
+    fn title_id(&self) -> String {
+        format!("{}-title", self.id.peek())
+    }
+
+    fn description_id(&self) -> String {
+        format!("{}-description", self.id.peek())
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct EmptyStateProps {
+    #[props(optional, default = "dxa-empty-state".into())]
+    class: String,
+
+    /// Event that is fired when the `section` wrapping the empty state is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// A placeholder shown when there is no content to display, such as an empty list or a
+/// page that failed to load.
+///
+/// The container is labelled by its [`EmptyStateTitle`] and described by its
+/// [`EmptyStateDescription`], so both should be rendered.
+#[component]
+pub fn EmptyState(props: EmptyStateProps) -> Element {
+    let ctx = use_empty_state_context();
+    empty_state_root(ctx, props)
+}
+
+fn use_empty_state_context() -> EmptyStateContext {
+    let aria_id = use_aria_id();
+    use_context_provider(|| EmptyStateContext {
+        id: Signal::new(aria_id),
+    })
+}
+
+/// Render the root of an empty state, shared by [`EmptyState`] and [`ErrorFallback`].
+fn empty_state_root(ctx: EmptyStateContext, props: EmptyStateProps) -> Element {
+    rsx! {
+        section {
+            class: "{props.class}",
+            aria_labelledby: "{ctx.title_id()}",
+            aria_describedby: "{ctx.description_id()}",
+            onmounted: move |data| props.on_mounted.call(data),
+            "data-part": "root",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct EmptyStateIconProps {
+    #[props(optional, default = "dxa-empty-state-icon".into())]
+    class: String,
+
+    /// Event that is fired when the icon container is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// A decorative illustration for an [`EmptyState`], hidden from assistive technology.
+#[component]
+pub fn EmptyStateIcon(props: EmptyStateIconProps) -> Element {
+    rsx! {
+        div {
+            class: "{props.class}",
+            aria_hidden: "true",
+            onmounted: move |data| props.on_mounted.call(data),
+            "data-part": "icon",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct EmptyStateTitleProps {
+    #[props(optional, default = "dxa-empty-state-title".into())]
+    class: String,
+
+    /// The heading level of the title, from 1 to 6.
+    /// Defaults to `2`.
+    #[props(optional, default = 2)]
+    level: u8,
+
+    /// Event that is fired when the title heading is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// The heading of an [`EmptyState`], used as its accessible name.
+#[component]
+pub fn EmptyStateTitle(props: EmptyStateTitleProps) -> Element {
+    let id = use_context::<EmptyStateContext>().title_id();
+    let EmptyStateTitleProps {
+        class,
+        level,
+        on_mounted,
+        attributes,
+        children,
+    } = props;
+    let on_mounted = move |data| on_mounted.call(data);
+
+    match level.clamp(1, 6) {
+        1 => rsx! {
+            h1 {
+                class: "{class}",
+                id: "{id}",
+                onmounted: on_mounted,
+                "data-part": "title",
+                ..attributes,
+                {children}
+            }
+        },
+        2 => rsx! {
+            h2 {
+                class: "{class}",
+                id: "{id}",
+                onmounted: on_mounted,
+                "data-part": "title",
+                ..attributes,
+                {children}
+            }
+        },
+        3 => rsx! {
+            h3 {
+                class: "{class}",
+                id: "{id}",
+                onmounted: on_mounted,
+                "data-part": "title",
+                ..attributes,
+                {children}
+            }
+        },
+        4 => rsx! {
+            h4 {
+                class: "{class}",
+                id: "{id}",
+                onmounted: on_mounted,
+                "data-part": "title",
+                ..attributes,
+                {children}
+            }
+        },
+        5 => rsx! {
+            h5 {
+                class: "{class}",
+                id: "{id}",
+                onmounted: on_mounted,
+                "data-part": "title",
+                ..attributes,
+                {children}
+            }
+        },
+        _ => rsx! {
+            h6 {
+                class: "{class}",
+                id: "{id}",
+                onmounted: on_mounted,
+                "data-part": "title",
+                ..attributes,
+                {children}
+            }
+        },
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct EmptyStateDescriptionProps {
+    #[props(optional, default = "dxa-empty-state-description".into())]
+    class: String,
+
+    /// Event that is fired when the description paragraph is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// The explanation of an [`EmptyState`], used as its accessible description.
+#[component]
+pub fn EmptyStateDescription(props: EmptyStateDescriptionProps) -> Element {
+    let id = use_context::<EmptyStateContext>().description_id();
+
+    rsx! {
+        p {
+            class: "{props.class}",
+            id: "{id}",
+            onmounted: move |data| props.on_mounted.call(data),
+            "data-part": "description",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct EmptyStateActionsProps {
+    #[props(optional, default = "dxa-empty-state-actions".into())]
+    class: String,
+
+    /// Event that is fired when the actions container is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// The actions a user can take from an [`EmptyState`], such as creating the first item.
+#[component]
+pub fn EmptyStateActions(props: EmptyStateActionsProps) -> Element {
+    rsx! {
+        div {
+            class: "{props.class}",
+            onmounted: move |data| props.on_mounted.call(data),
+            "data-part": "actions",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ErrorFallbackProps {
+    #[props(optional, default = "dxa-empty-state".into())]
+    class: String,
+
+    /// The message of the error captured by an `ErrorBoundary`.
+    error: String,
+
+    /// The title shown above the error.
+    #[props(optional, default = "Something went wrong".into())]
+    title: String,
+
+    /// The heading level of the title, from 1 to 6.
+    /// Defaults to `2`.
+    #[props(optional, default = 2)]
+    level: u8,
+
+    /// The label of the retry button.
+    #[props(optional, default = "Try again".into())]
+    retry_label: String,
+
+    /// Event that is fired when the user asks to retry.
+    ///
+    /// The retry button is only shown when this is supplied.
+    on_retry: Option<EventHandler<()>>,
+
+    /// Event that is fired when the `section` wrapping the fallback is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    /// Attributes applied to the retry button.
+    #[props(optional)]
+    retry_attributes: Vec<Attribute>,
+}
+
+/// An [`EmptyState`] for errors captured by an `ErrorBoundary`.
+///
+/// It renders an [`EmptyStateTitle`], an [`EmptyStateDescription`], and, when `on_retry` is
+/// supplied, [`EmptyStateActions`] with a retry button.
+///
+/// ```rust, ignore
+/// ErrorBoundary {
+///     handle_error: |error| rsx! { ErrorFallback { error: error.to_string() } },
+///     Dashboard {}
+/// }
+/// ```
+#[component]
+pub fn ErrorFallback(props: ErrorFallbackProps) -> Element {
+    let ctx = use_empty_state_context();
+
+    let children = rsx! {
+        EmptyStateTitle { level: props.level, "{props.title}" }
+        EmptyStateDescription { "{props.error}" }
+        if let Some(on_retry) = props.on_retry {
+            EmptyStateActions {
+                button {
+                    onclick: move |_| on_retry.call(()),
+                    "data-part": "retry",
+                    ..props.retry_attributes,
+                    "{props.retry_label}"
+                }
+            }
+        }
+    };
+
+    empty_state_root(
+        ctx,
+        EmptyStateProps {
+            class: props.class,
+            on_mounted: props.on_mounted,
+            attributes: props.attributes,
+            children,
+        },
+    )
+}
@@ -0,0 +1,35 @@
+use dioxus::prelude::*;
+
+/// The prefix of ids generated inside an [`IdProvider`].
+#[derive(Clone, PartialEq)]
+pub(crate) struct IdPrefix(pub(crate) String);
+
+#[derive(Props, Clone, PartialEq)]
+pub struct IdProviderProps {
+    /// The prefix of every id generated for the components inside this provider.
+    prefix: String,
+
+    children: Element,
+}
+
+/// Namespace the ids that components generate for `aria-labelledby`, `aria-controls`, and
+/// similar references.
+///
+/// Ids are counted per app, so two apps mounted on the same page generate the same ids. Wrap
+/// each app in an `IdProvider` with a distinct prefix to keep them apart. The ids stay
+/// deterministic, so server rendered ids still match on the client.
+///
+/// ```rust, ignore
+/// IdProvider {
+///     prefix: "sidebar",
+///     Sidebar {}
+/// }
+/// ```
+#[component]
+pub fn IdProvider(props: IdProviderProps) -> Element {
+    use_context_provider(|| IdPrefix(props.prefix.clone()));
+
+    rsx! {
+        {props.children}
+    }
+}
@@ -1,8 +1,15 @@
 use dioxus::{
     dioxus_core::use_hook,
-    signals::{GlobalSignal, Signal},
+    prelude::{eval, spawn_forever, try_consume_context, use_context, use_signal},
+    signals::{GlobalSignal, ReadOnlySignal, Readable, Signal, Writable},
 };
 
+mod id_provider;
+pub use id_provider::*;
+
+mod motion_provider;
+pub use motion_provider::*;
+
 mod button;
 pub use button::*;
 
@@ -12,6 +19,24 @@ pub use alert::*;
 mod accordion;
 pub use accordion::*;
 
+mod toggle_button;
+pub use toggle_button::*;
+
+mod switch;
+pub use switch::*;
+
+mod tabs;
+pub use tabs::*;
+
+mod empty_state;
+pub use empty_state::*;
+
+mod listbox;
+pub use listbox::*;
+
+mod listener;
+pub(crate) use listener::*;
+
 #[derive(Clone, PartialEq)]
 pub struct Icon {
     pub src: String,
@@ -19,11 +44,218 @@ pub struct Icon {
     pub width: u32,
 }
 
+/// The kind of input that caused a state change.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum InteractionSource {
+    Pointer,
+    Keyboard,
+}
+
+/// A change of an on/off state, such as a [`ToggleButton`] being pressed or a [`Switch`] being
+/// turned on.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ToggleChange {
+    /// The new state.
+    pub value: bool,
+    /// The state before the change, useful for undoing it.
+    pub previous: bool,
+    /// What caused the change.
+    pub source: InteractionSource,
+}
+
+/// The open state of the nearest disclosure, such as an [`Accordion`].
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct OpenState(pub(crate) ReadOnlySignal<bool>);
+
+/// Read whether the nearest disclosure, such as an [`Accordion`], is open.
+///
+/// This lets components rendered inside a trigger, like an indicator icon, switch their content
+/// instead of rotating it with CSS.
+///
+/// # Panics
+/// Panics when called outside of a disclosure.
+pub fn use_open_state() -> ReadOnlySignal<bool> {
+    use_context::<OpenState>().0
+}
+
+static REDUCED_MOTION: GlobalSignal<bool> = Signal::global(|| false);
+static REDUCED_MOTION_TRACKED: GlobalSignal<bool> = Signal::global(|| false);
+
+/// Track whether the user prefers reduced motion through `prefers-reduced-motion`.
+///
+/// This is `false` until the preference has been read on the client, and follows changes to it
+/// afterwards.
+pub fn use_reduced_motion() -> ReadOnlySignal<bool> {
+    use_hook(|| {
+        if *REDUCED_MOTION_TRACKED.peek() {
+            return;
+        }
+        *REDUCED_MOTION_TRACKED.write() = true;
+
+        let query = ListenerTarget::MediaQuery("(prefers-reduced-motion: reduce)".to_string());
+        spawn_forever(listen(query, "change", false, "event.matches", |matches| {
+            *REDUCED_MOTION.write() = matches == "true";
+        }));
+    });
+
+    REDUCED_MOTION.signal().into()
+}
+
+static INPUT_MODALITY: GlobalSignal<InteractionSource> =
+    Signal::global(|| InteractionSource::Pointer);
+static INPUT_MODALITY_TRACKED: GlobalSignal<bool> = Signal::global(|| false);
+
+/// Track the kind of input the user last interacted with anywhere on the page.
+///
+/// This is [`InteractionSource::Pointer`] until the first key press.
+pub fn use_input_modality() -> ReadOnlySignal<InteractionSource> {
+    use_track_input_modality();
+    INPUT_MODALITY.signal().into()
+}
+
+/// The kind of input the user last interacted with, without subscribing to changes.
+///
+/// Components that read this must call [`use_track_input_modality`].
+pub(crate) fn input_modality() -> InteractionSource {
+    *INPUT_MODALITY.peek()
+}
+
+/// Start listening for the input modality on the page, once per app.
+pub(crate) fn use_track_input_modality() {
+    use_hook(|| {
+        if *INPUT_MODALITY_TRACKED.peek() {
+            return;
+        }
+        *INPUT_MODALITY_TRACKED.write() = true;
+
+        let set_modality = |modality| {
+            if *INPUT_MODALITY.peek() != modality {
+                *INPUT_MODALITY.write() = modality;
+            }
+        };
+
+        // Shortcuts with modifiers don't move focus, so they don't count as keyboard use.
+        let key_payload = "event.metaKey || event.altKey || event.ctrlKey ? '' : 'keyboard'";
+        spawn_forever(listen(
+            ListenerTarget::Document,
+            "keydown",
+            true,
+            key_payload,
+            move |modality| {
+                if modality == "keyboard" {
+                    set_modality(InteractionSource::Keyboard);
+                }
+            },
+        ));
+        spawn_forever(listen(
+            ListenerTarget::Document,
+            "pointerdown",
+            true,
+            "'pointer'",
+            move |_| set_modality(InteractionSource::Pointer),
+        ));
+    });
+}
+
+/// Whether an element was focused through the keyboard, for styling focus rings.
+///
+/// Unlike `:focus-visible`, this also holds when focus is moved programmatically in response to
+/// a key press, such as with a roving tabindex.
+#[derive(Clone, Copy, PartialEq)]
+pub struct FocusVisible {
+    focused: Signal<bool>,
+}
+
+impl FocusVisible {
+    /// Call from the element's `onfocus` handler.
+    pub fn focus(&mut self) {
+        self.focused.set(true);
+    }
+
+    /// Call from the element's `onblur` handler.
+    pub fn blur(&mut self) {
+        self.focused.set(false);
+    }
+
+    /// Whether the element is focused through the keyboard.
+    ///
+    /// The modality is read when rendering rather than on focus, since the key press that
+    /// moved focus can be reported after the focus event.
+    pub fn is_visible(&self) -> bool {
+        (self.focused)() && INPUT_MODALITY() == InteractionSource::Keyboard
+    }
+
+    /// The value of the `data-focus-visible` attribute.
+    pub fn attr(&self) -> Option<&'static str> {
+        self.is_visible().then_some("true")
+    }
+}
+
+/// Track whether an element is focused through the keyboard.
+///
+/// ```rust, ignore
+/// let mut focus_visible = use_focus_visible();
+/// rsx! {
+///     button {
+///         onfocus: move |_| focus_visible.focus(),
+///         onblur: move |_| focus_visible.blur(),
+///         "data-focus-visible": focus_visible.attr(),
+///     }
+/// }
+/// ```
+pub fn use_focus_visible() -> FocusVisible {
+    use_track_input_modality();
+    FocusVisible {
+        focused: use_signal(|| false),
+    }
+}
+
 static ARIA_ID_COUNT: GlobalSignal<u32> = Signal::global(|| 0);
 pub(crate) fn use_aria_id() -> String {
     use_hook(|| {
         let id = ARIA_ID_COUNT();
         *ARIA_ID_COUNT.write() += 1;
-        format!("dxa-aria-{}", id)
+
+        match try_consume_context::<IdPrefix>() {
+            Some(IdPrefix(prefix)) => format!("{}-{}", prefix, id),
+            None => format!("dxa-aria-{}", id),
+        }
     })
-}
\ No newline at end of file
+}
+
+/// Keep the element with `id` at `hidden="until-found"` while it is hidden, and call `on_match`
+/// when find-in-page is about to reveal it.
+///
+/// Dioxus renders `hidden` as a boolean attribute, so the value is swapped in the DOM whenever
+/// the element is hidden again. Browsers without `until-found` support treat the element as
+/// plain `hidden`.
+///
+/// This runs until the returned future is dropped. Spawn it from the element's `onmounted` and
+/// remove the task before spawning another one when the element is mounted again.
+pub(crate) async fn hide_until_found(id: String, mut on_match: impl FnMut()) {
+    let eval = eval(
+        r#"
+        const id = await dioxus.recv();
+        const element = document.getElementById(id);
+        if (element) {
+            const untilFound = () => {
+                const hidden = element.getAttribute("hidden");
+                if (hidden !== null && hidden !== "until-found") {
+                    element.setAttribute("hidden", "until-found");
+                }
+            };
+            const observer = new MutationObserver(untilFound);
+            observer.observe(element, { attributes: true, attributeFilter: ["hidden"] });
+            untilFound();
+
+            await dioxus.recv();
+            observer.disconnect();
+        }
+        "#,
+    );
+    eval.send(id.clone().into()).ok();
+    let _until_found = StopOnDrop(eval);
+
+    let target = ListenerTarget::Element(id);
+    listen(target, "beforematch", false, "''", move |_| on_match()).await;
+}
@@ -0,0 +1,515 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::{use_aria_id, use_focus_visible};
+
+/// Whether a [`Listbox`] allows one or many options to be selected.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum SelectionMode {
+    #[default]
+    Single,
+    Multiple,
+}
+
+#[derive(Clone)]
+struct ListboxOption {
+    id: String,
+    value: String,
+    text_value: String,
+    disabled: bool,
+    mounted: Option<Rc<MountedData>>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct ListboxContext {
+    id: Signal<String>,
+    selection_mode: Signal<SelectionMode>,
+    value: Signal<Vec<String>>,
+    on_value_change: Signal<EventHandler<Vec<String>>>,
+    on_highlight_change: Signal<EventHandler<Option<String>>>,
+    active: Signal<Option<String>>,
+    options: Signal<Vec<ListboxOption>>,
+    sort_task: Signal<Option<Task>>,
+}
+
+impl ListboxContext {
+    /// Register an option, or update it if its props changed since the last render.
+    fn register(&mut self, id: &str, value: &str, text_value: &str, disabled: bool) {
+        let existing = self.options.peek().iter().position(|o| o.id == id);
+        let Some(index) = existing else {
+            self.options.write().push(ListboxOption {
+                id: id.to_string(),
+                value: value.to_string(),
+                text_value: text_value.to_string(),
+                disabled,
+                mounted: None,
+            });
+            return;
+        };
+
+        let previous = self.options.peek()[index].clone();
+        if previous.value == value
+            && previous.text_value == text_value
+            && previous.disabled == disabled
+        {
+            return;
+        }
+
+        let mut options = self.options.write();
+        options[index].value = value.to_string();
+        options[index].text_value = text_value.to_string();
+        options[index].disabled = disabled;
+        drop(options);
+
+        if self.active.peek().as_ref() == Some(&previous.value) {
+            self.active.set(Some(value.to_string()));
+        }
+    }
+
+    /// Reorder the options to match the order of their elements in the DOM.
+    ///
+    /// Options register in the order they first render, so an option inserted between
+    /// existing ones would otherwise be placed last. Options mounted in the same frame share a
+    /// single pass.
+    fn sort(mut self) {
+        if let Some(task) = self.sort_task.write().take() {
+            remove_future(task);
+        }
+
+        let id = self.id.peek().clone();
+        let task = spawn(async move {
+            let mut eval = eval(
+                r#"
+                const id = await dioxus.recv();
+                await new Promise((resolve) => requestAnimationFrame(resolve));
+                const root = document.getElementById(id);
+                const options = root ? root.querySelectorAll('[role="option"]') : [];
+                dioxus.send(Array.from(options, (option) => option.id));
+                "#,
+            );
+            eval.send(id.into()).ok();
+            let order = eval.recv().await;
+            self.sort_task.set(None);
+            let Ok(order) = order else {
+                return;
+            };
+
+            let order: Vec<&str> = order
+                .as_array()
+                .map(|ids| ids.iter().filter_map(|id| id.as_str()).collect())
+                .unwrap_or_default();
+            let position = |option: &ListboxOption| {
+                order
+                    .iter()
+                    .position(|id| *id == option.id)
+                    .unwrap_or(order.len())
+            };
+
+            let sorted = self
+                .options
+                .peek()
+                .windows(2)
+                .all(|pair| position(&pair[0]) <= position(&pair[1]));
+            if !sorted {
+                self.options.write().sort_by_key(position);
+            }
+        });
+        self.sort_task.set(Some(task));
+    }
+
+    fn is_multiple(&self) -> bool {
+        *self.selection_mode.peek() == SelectionMode::Multiple
+    }
+
+    fn active_index(&self) -> Option<usize> {
+        let active = self.active.peek();
+        let active = active.as_ref()?;
+        self.options.peek().iter().position(|o| &o.value == active)
+    }
+
+    /// Find the first enabled option in `indices`.
+    fn find_enabled(&self, mut indices: impl Iterator<Item = usize>) -> Option<usize> {
+        let options = self.options.peek();
+        indices.find(|&i| !options[i].disabled)
+    }
+
+    fn change_value(&self, value: Vec<String>) {
+        self.on_value_change.peek().call(value);
+    }
+
+    fn toggle(&self, value: &str) {
+        let mut new_value = self.value.peek().clone();
+        match new_value.iter().position(|v| v == value) {
+            Some(i) => {
+                new_value.remove(i);
+            }
+            None => new_value.push(value.to_string()),
+        }
+        self.change_value(new_value);
+    }
+
+    /// Make the option with `value` active and report the change.
+    fn highlight(&mut self, value: Option<String>) {
+        if *self.active.peek() == value {
+            return;
+        }
+        self.active.set(value.clone());
+        self.on_highlight_change.peek().call(value);
+    }
+
+    fn scroll_into_view(&self, index: usize) {
+        if let Some(mounted) = self.options.peek()[index].mounted.clone() {
+            spawn(async move {
+                _ = mounted.scroll_to(ScrollBehavior::Smooth).await;
+            });
+        }
+    }
+
+    /// Make the option at `index` active, scrolling it into view.
+    ///
+    /// In single selection mode the selection follows the active option. In multiple selection
+    /// mode, `toggle` also toggles the option's selection.
+    fn activate(&mut self, index: usize, toggle: bool) {
+        let value = self.options.peek()[index].value.clone();
+        self.highlight(Some(value.clone()));
+
+        if !self.is_multiple() {
+            self.change_value(vec![value]);
+        } else if toggle {
+            self.toggle(&value);
+        }
+
+        self.scroll_into_view(index);
+    }
+
+    /// Find the next enabled option after the active one whose text starts with `c`.
+    fn typeahead(&self, c: &str) -> Option<usize> {
+        let c = c.to_lowercase();
+        let len = self.options.peek().len();
+        let start = self.active_index().map(|i| i + 1).unwrap_or(0);
+
+        let options = self.options.peek();
+        (start..len)
+            .chain(0..start)
+            .find(|&i| !options[i].disabled && options[i].text_value.to_lowercase().starts_with(&c))
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ListboxProps {
+    #[props(optional, default = "dxa-listbox".into())]
+    class: String,
+
+    /// The accessible label of the listbox.
+    label: String,
+
+    /// Whether one or many options can be selected.
+    /// Defaults to [`SelectionMode::Single`].
+    #[props(optional)]
+    selection_mode: SelectionMode,
+
+    /// The number of options PageUp and PageDown move by.
+    /// Defaults to `10`.
+    #[props(optional, default = 10)]
+    page_size: usize,
+
+    /// The values of the selected options.
+    value: Vec<String>,
+
+    /// Event that is fired with the new selection when the user changes it.
+    #[props(optional)]
+    on_value_change: EventHandler<Vec<String>>,
+
+    /// The value of the highlighted option.
+    ///
+    /// When set, the highlight follows this value and the option is scrolled into view.
+    /// Otherwise the listbox manages the highlight itself.
+    highlighted_value: Option<String>,
+
+    /// Event that is fired when the highlighted option changes through keyboard or pointer
+    /// interaction.
+    #[props(optional)]
+    on_highlight_change: EventHandler<Option<String>>,
+
+    /// Event that is fired when the listbox element is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// The `Listbox` ARIA pattern.
+///
+/// A permanently visible list of options. The listbox keeps focus and points to the active
+/// [`ListboxItem`] with `aria-activedescendant`.
+///
+/// - Arrow keys, Home, and End move the active option, skipping disabled options.
+/// - PageUp and PageDown move the active option by `page_size`, stopping at the first and last
+///   enabled options.
+/// - Hovering an option with the pointer makes it active.
+/// - Typing a character moves to the next option whose text starts with that character.
+/// - In single selection mode, the selection follows the active option.
+/// - In multiple selection mode, Space toggles the active option and Shift + Arrow moves and
+///   toggles the next option.
+///
+/// The listbox is marked with `data-focus-visible` when focused through the keyboard.
+///
+/// See the [listbox pattern](https://www.w3.org/WAI/ARIA/apg/patterns/listbox/).
+#[component]
+pub fn Listbox(props: ListboxProps) -> Element {
+    let aria_id = use_aria_id();
+    let mut ctx = use_context_provider(|| ListboxContext {
+        id: Signal::new(aria_id),
+        selection_mode: Signal::new(props.selection_mode),
+        value: Signal::new(props.value.clone()),
+        on_value_change: Signal::new(props.on_value_change),
+        on_highlight_change: Signal::new(props.on_highlight_change),
+        active: Signal::new(props.highlighted_value.clone()),
+        options: Signal::new(Vec::new()),
+        sort_task: Signal::new(None),
+    });
+
+    if *ctx.selection_mode.peek() != props.selection_mode {
+        ctx.selection_mode.set(props.selection_mode);
+    }
+
+    if *ctx.value.peek() != props.value {
+        ctx.value.set(props.value.clone());
+    }
+
+    if *ctx.on_value_change.peek() != props.on_value_change {
+        ctx.on_value_change.set(props.on_value_change);
+    }
+
+    if *ctx.on_highlight_change.peek() != props.on_highlight_change {
+        ctx.on_highlight_change.set(props.on_highlight_change);
+    }
+
+    if let Some(highlighted) = &props.highlighted_value {
+        if ctx.active.peek().as_ref() != Some(highlighted) {
+            ctx.active.set(Some(highlighted.clone()));
+            let index = ctx
+                .options
+                .peek()
+                .iter()
+                .position(|o| &o.value == highlighted);
+            if let Some(index) = index {
+                ctx.scroll_into_view(index);
+            }
+        }
+    }
+
+    let on_key_down = move |evt: KeyboardEvent| {
+        let len = ctx.options.peek().len();
+        let current = ctx.active_index();
+        let modifiers = evt.modifiers();
+        let extend = modifiers.contains(Modifiers::SHIFT) && ctx.is_multiple();
+
+        let target = match evt.key() {
+            Key::ArrowDown => match current {
+                Some(i) => ctx.find_enabled(i + 1..len),
+                None => ctx.find_enabled(0..len),
+            },
+            Key::ArrowUp => match current {
+                Some(i) => ctx.find_enabled((0..i).rev()),
+                None => ctx.find_enabled((0..len).rev()),
+            },
+            Key::PageDown => match current {
+                Some(i) => {
+                    let target = i.saturating_add(props.page_size).min(len - 1);
+                    ctx.find_enabled(target..len)
+                        .or_else(|| ctx.find_enabled((i..target).rev()))
+                }
+                None => ctx.find_enabled(0..len),
+            },
+            Key::PageUp => match current {
+                Some(i) => {
+                    let target = i.saturating_sub(props.page_size);
+                    ctx.find_enabled((0..=target).rev())
+                        .or_else(|| ctx.find_enabled(target + 1..=i))
+                }
+                None => ctx.find_enabled((0..len).rev()),
+            },
+            Key::Home => ctx.find_enabled(0..len),
+            Key::End => ctx.find_enabled((0..len).rev()),
+            Key::Character(c) if c == " " => {
+                if let Some(i) = current {
+                    ctx.activate(i, true);
+                }
+                return;
+            }
+            Key::Character(c)
+                if !modifiers.contains(Modifiers::CONTROL)
+                    && !modifiers.contains(Modifiers::META) =>
+            {
+                ctx.typeahead(&c)
+            }
+            _ => return,
+        };
+
+        if let Some(target) = target {
+            ctx.activate(target, extend);
+        }
+    };
+
+    let mut focus_visible = use_focus_visible();
+
+    // Start from the selected option, or the first enabled one, when focus enters the listbox.
+    let on_focus = move |_| {
+        focus_visible.focus();
+        if ctx.active.peek().is_some() {
+            return;
+        }
+
+        let selected = ctx.value.peek().first().cloned();
+        let index = ctx
+            .options
+            .peek()
+            .iter()
+            .position(|o| Some(&o.value) == selected.as_ref())
+            .or_else(|| ctx.find_enabled(0..ctx.options.peek().len()));
+
+        if let Some(index) = index {
+            let value = ctx.options.peek()[index].value.clone();
+            ctx.highlight(Some(value));
+        }
+    };
+
+    let active_id = (ctx.active)().and_then(|active| {
+        ctx.options
+            .read()
+            .iter()
+            .find(|o| o.value == active)
+            .map(|o| o.id.clone())
+    });
+
+    let multiselectable_val = match props.selection_mode {
+        SelectionMode::Single => None,
+        SelectionMode::Multiple => Some("true"),
+    };
+
+    rsx! {
+        div {
+            class: "{props.class}",
+            id: "{ctx.id}",
+            role: "listbox",
+            tabindex: "0",
+            // Events
+            onkeydown: on_key_down,
+            onfocus: on_focus,
+            onblur: move |_| focus_visible.blur(),
+            onmounted: move |data| props.on_mounted.call(data),
+            // Aria
+            aria_label: "{props.label}",
+            aria_multiselectable: multiselectable_val,
+            aria_activedescendant: active_id,
+
+            "data-focus-visible": focus_visible.attr(),
+            "data-part": "root",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ListboxItemProps {
+    #[props(optional, default = "dxa-listbox-item".into())]
+    class: String,
+
+    /// The value reported in the [`Listbox`]'s selection.
+    value: String,
+
+    /// The text used for typeahead. Defaults to `value`.
+    text_value: Option<String>,
+
+    /// Optionally mark this option as disabled.
+    /// Defaults to `false`.
+    #[props(optional, default = false)]
+    disabled: bool,
+
+    /// Event that is fired when the option element is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// A single option within a [`Listbox`].
+///
+/// The active option is marked with `data-highlighted` for styling.
+#[component]
+pub fn ListboxItem(props: ListboxItemProps) -> Element {
+    let mut ctx = use_context::<ListboxContext>();
+    let id = use_aria_id();
+
+    let text_value = props.text_value.as_ref().unwrap_or(&props.value);
+    ctx.register(&id, &props.value, text_value, props.disabled);
+
+    let drop_id = id.clone();
+    use_drop(move || {
+        ctx.options.write().retain(|o| o.id != drop_id);
+    });
+
+    let is_selected = (ctx.value)().contains(&props.value);
+    let is_active = (ctx.active)().as_ref() == Some(&props.value);
+
+    let click_id = id.clone();
+    let on_click = move |_| {
+        if props.disabled {
+            return;
+        }
+        let index = ctx.options.peek().iter().position(|o| o.id == click_id);
+        if let Some(index) = index {
+            ctx.activate(index, true);
+        }
+    };
+
+    let hover_value = props.value.clone();
+    let on_pointer_move = move |_| {
+        if !props.disabled {
+            ctx.highlight(Some(hover_value.clone()));
+        }
+    };
+
+    let mounted_id = id.clone();
+    let on_mounted = move |evt: MountedEvent| {
+        if let Some(option) = ctx.options.write().iter_mut().find(|o| o.id == mounted_id) {
+            option.mounted = Some(evt.data());
+        }
+        ctx.sort();
+        props.on_mounted.call(evt);
+    };
+
+    let selected_val = match is_selected {
+        true => "true",
+        false => "false",
+    };
+
+    rsx! {
+        div {
+            class: "{props.class}",
+            id: "{id}",
+            role: "option",
+            // Events
+            onclick: on_click,
+            onpointermove: on_pointer_move,
+            onmounted: on_mounted,
+            // Aria
+            aria_selected: selected_val,
+            aria_disabled: props.disabled.then_some("true"),
+
+            "data-highlighted": is_active.then_some("true"),
+            "data-part": "item",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use dioxus::prelude::*;
+
+/// Where [`listen`] registers its listener.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum ListenerTarget {
+    Document,
+    /// The element with this id, which must be mounted when the listener is registered.
+    Element(String),
+    /// The media query list for a query such as `"(prefers-reduced-motion: reduce)"`.
+    ///
+    /// The current state of the query is reported once as soon as the listener is registered.
+    MediaQuery(String),
+}
+
+impl ListenerTarget {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Document => "document",
+            Self::Element(_) => "element",
+            Self::MediaQuery(_) => "media-query",
+        }
+    }
+
+    fn argument(&self) -> &str {
+        match self {
+            Self::Document => "",
+            Self::Element(id) => id,
+            Self::MediaQuery(query) => query,
+        }
+    }
+}
+
+/// Sends a message to an eval when dropped, which its script awaits to undo what it set up,
+/// such as removing the listener registered by [`listen`].
+pub(crate) struct StopOnDrop(pub(crate) UseEval);
+
+impl Drop for StopOnDrop {
+    fn drop(&mut self) {
+        self.0.send(true.into()).ok();
+    }
+}
+
+/// Register a listener and call `handler` with its payload until the returned future is dropped.
+///
+/// `payload` is a JavaScript expression over `event` that is converted to a string and passed
+/// to `handler`, e.g. `"event.key"`. The listener is passive, since `handler` runs after the
+/// event has been dispatched and can't prevent its default action.
+///
+/// Spawn it with `spawn` to tie the listener to the current component, or with `spawn_forever`
+/// for a listener that lives as long as the app.
+pub(crate) async fn listen(
+    target: ListenerTarget,
+    event: &'static str,
+    capture: bool,
+    payload: &'static str,
+    mut handler: impl FnMut(String),
+) {
+    let eval = eval(
+        &r#"
+        const kind = await dioxus.recv();
+        const argument = await dioxus.recv();
+        const eventName = await dioxus.recv();
+        const capture = await dioxus.recv();
+
+        const target = {
+            "document": () => document,
+            "element": () => document.getElementById(argument),
+            "media-query": () => window.matchMedia(argument),
+        }[kind]();
+
+        if (target) {
+            const listener = (event) => dioxus.send(String(PAYLOAD));
+            target.addEventListener(eventName, listener, { capture, passive: true });
+            if (kind === "media-query") {
+                listener(target);
+            }
+
+            await dioxus.recv();
+            target.removeEventListener(eventName, listener, { capture });
+        }
+        "#
+        .replace("PAYLOAD", payload),
+    );
+
+    let mut eval = StopOnDrop(eval);
+    eval.0.send(target.kind().into()).ok();
+    eval.0.send(target.argument().into()).ok();
+    eval.0.send(event.into()).ok();
+    eval.0.send(capture.into()).ok();
+
+    while let Ok(value) = eval.0.recv().await {
+        if let Some(value) = value.as_str() {
+            handler(value.to_string());
+        }
+    }
+}
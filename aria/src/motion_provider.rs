@@ -0,0 +1,42 @@
+use dioxus::prelude::*;
+
+use crate::use_reduced_motion;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MotionProviderProps {
+    #[props(optional, default = "dxa-motion-provider".into())]
+    class: String,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// Expose the user's motion preference to styles, at the root of an app.
+///
+/// The provider wraps its children in an element with `display: contents`, stamped with
+/// `data-reduced-motion` while the user prefers reduced motion, so stylesheets can disable
+/// transitions for everything inside it:
+///
+/// ```css
+/// [data-reduced-motion] * {
+///     transition: none;
+///     animation: none;
+/// }
+/// ```
+#[component]
+pub fn MotionProvider(props: MotionProviderProps) -> Element {
+    let reduced_motion = use_reduced_motion();
+
+    rsx! {
+        div {
+            class: "{props.class}",
+            style: "display: contents",
+            "data-reduced-motion": reduced_motion().then_some("true"),
+            "data-part": "root",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
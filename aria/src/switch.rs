@@ -0,0 +1,85 @@
+use dioxus::prelude::*;
+
+use crate::{input_modality, use_aria_id, use_track_input_modality, ButtonContent, ToggleChange};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SwitchProps {
+    #[props(optional, default = "dxa-switch".into())]
+    class: String,
+
+    /// The label and/or icon displayed in this switch.
+    #[props(into)]
+    content: ButtonContent,
+
+    /// Whether the switch is initially on.
+    /// Defaults to `false`.
+    #[props(optional, default = false)]
+    default_checked: bool,
+
+    /// Event that is fired whenever the switch is turned on or off.
+    #[props(optional)]
+    on_toggled: EventHandler<bool>,
+
+    /// Event that is fired alongside `on_toggled`, with the previous state and the kind of
+    /// input that caused the change.
+    #[props(optional)]
+    on_change: EventHandler<ToggleChange>,
+
+    /// Event that is fired when the switch button is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// The `Switch` ARIA pattern.
+///
+/// A switch represents an on/off value rather than a pressed action, and exposes its state
+/// through `aria-checked`. A visible label is associated with the switch through
+/// `aria-labelledby`, while icon-only switches use their `aria_label`.
+///
+/// See the [switch pattern](https://www.w3.org/WAI/ARIA/apg/patterns/switch/).
+#[component]
+pub fn Switch(props: SwitchProps) -> Element {
+    let label_id = use_aria_id();
+    let mut is_checked = use_signal(|| props.default_checked);
+    use_track_input_modality();
+
+    let on_click = move |_| {
+        let previous = is_checked();
+        is_checked.set(!previous);
+
+        props.on_toggled.call(!previous);
+        props.on_change.call(ToggleChange {
+            value: !previous,
+            previous,
+            source: input_modality(),
+        });
+    };
+
+    let aria_labelledby_val = props.content.visible_label().map(|_| label_id.clone());
+
+    let checked_val = match is_checked() {
+        true => "true",
+        false => "false",
+    };
+
+    rsx! {
+        button {
+            class: "{props.class}",
+            role: "switch",
+            // Events
+            onclick: on_click,
+            onmounted: move |data| props.on_mounted.call(data),
+            // Aria
+            aria_checked: checked_val,
+            aria_label: props.content.aria_label(),
+            aria_labelledby: aria_labelledby_val,
+
+            "data-part": "root",
+            ..props.attributes,
+            {props.content.render(&label_id)}
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use dioxus::prelude::*;
+
+use crate::{hide_until_found, use_aria_id, use_focus_visible, use_reduced_motion};
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct TabsContext {
+    id: Signal<String>,
+    selected: Signal<usize>,
+    previous: Signal<Option<usize>>,
+    transition: Signal<Option<Task>>,
+    skip_animation: Signal<bool>,
+    on_select: Signal<EventHandler<usize>>,
+    reveal_on_find: Signal<bool>,
+    tabs: Signal<BTreeMap<usize, Rc<MountedData>>>,
+}
+
+impl TabsContext {
+    fn tab_id(&self, index: usize) -> String {
+        format!("{}-tab-{}", self.id.peek(), index)
+    }
+
+    fn panel_id(&self, index: usize) -> String {
+        format!("{}-panel-{}", self.id.peek(), index)
+    }
+
+    /// Select `index`, keeping the previously selected panel visible until the animations of
+    /// both panels have finished.
+    fn select(mut self, index: usize) {
+        let previous = *self.selected.peek();
+        self.selected.set(index);
+
+        if let Some(task) = self.transition.write().take() {
+            remove_future(task);
+        }
+
+        if *self.skip_animation.peek() {
+            self.previous.set(None);
+            return;
+        }
+        self.previous.set(Some(previous));
+
+        let ids = [self.panel_id(previous), self.panel_id(index)];
+        let task = spawn(async move {
+            let mut eval = eval(
+                r#"
+                const ids = await dioxus.recv();
+                await new Promise((resolve) => requestAnimationFrame(resolve));
+                const animations = ids
+                    .map((id) => document.getElementById(id))
+                    .filter((element) => element)
+                    .flatMap((element) => element.getAnimations({ subtree: true }));
+                await Promise.allSettled(animations.map((a) => a.finished));
+                dioxus.send(true);
+                "#,
+            );
+            eval.send(ids.to_vec().into()).ok();
+            eval.recv().await.ok();
+
+            self.previous.set(None);
+            self.transition.set(None);
+        });
+        self.transition.set(Some(task));
+    }
+
+    /// The direction a panel moves in when the selection changes.
+    ///
+    /// The newly selected panel moves `from-start` or `from-end` and the previously selected
+    /// panel moves `to-start` or `to-end`, depending on the order of the two tabs.
+    fn motion(&self, index: usize) -> Option<&'static str> {
+        let selected = (self.selected)();
+        let previous = (self.previous)()?;
+        let forward = selected > previous;
+
+        if previous == selected {
+            None
+        } else if index == selected {
+            Some(if forward { "from-end" } else { "from-start" })
+        } else if index == previous {
+            Some(if forward { "to-start" } else { "to-end" })
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TabsProps {
+    /// The index of the currently selected tab.
+    selected: usize,
+
+    /// Event that is fired when a tab is selected by click or keyboard.
+    #[props(optional)]
+    on_select: EventHandler<usize>,
+
+    /// Render unselected panels with `hidden="until-found"` so the browser's find-in-page can
+    /// search them. Finding a match selects the panel's tab through `on_select`.
+    /// Defaults to `false`.
+    #[props(optional, default = false)]
+    reveal_on_find: bool,
+
+    /// Switch panels immediately when the user prefers reduced motion, instead of waiting for
+    /// the animations of the leaving panel.
+    /// Defaults to `true`.
+    #[props(optional, default = true)]
+    respect_reduced_motion: bool,
+
+    children: Element,
+}
+
+/// The `Tabs` ARIA pattern.
+///
+/// `Tabs` renders no element of its own. It pairs every [`Tab`] with the [`Tabpanel`] of the
+/// same index so that `aria-controls` and `aria-labelledby` always match, and keeps the
+/// selection controlled through `selected` and `on_select`.
+///
+/// Tabs are activated automatically when focused with the arrow keys, Home, or End.
+///
+/// When the selection changes, the entering and leaving [`Tabpanel`]s are stamped with a
+/// `data-motion` attribute so their content can be animated in the direction of travel. The
+/// leaving panel stays visible until the animations of both panels have finished, unless the
+/// user prefers reduced motion.
+///
+/// See the [tabs pattern](https://www.w3.org/WAI/ARIA/apg/patterns/tabs/).
+#[component]
+pub fn Tabs(props: TabsProps) -> Element {
+    let aria_id = use_aria_id();
+    let reduced_motion = use_reduced_motion();
+    let skip_animation = props.respect_reduced_motion && reduced_motion();
+
+    let mut ctx = use_context_provider(|| TabsContext {
+        id: Signal::new(aria_id),
+        selected: Signal::new(props.selected),
+        previous: Signal::new(None),
+        transition: Signal::new(None),
+        skip_animation: Signal::new(skip_animation),
+        on_select: Signal::new(props.on_select),
+        reveal_on_find: Signal::new(props.reveal_on_find),
+        tabs: Signal::new(BTreeMap::new()),
+    });
+
+    if *ctx.skip_animation.peek() != skip_animation {
+        ctx.skip_animation.set(skip_animation);
+    }
+
+    if *ctx.selected.peek() != props.selected {
+        ctx.select(props.selected);
+    }
+
+    if *ctx.on_select.peek() != props.on_select {
+        ctx.on_select.set(props.on_select);
+    }
+
+    if *ctx.reveal_on_find.peek() != props.reveal_on_find {
+        ctx.reveal_on_find.set(props.reveal_on_find);
+    }
+
+    rsx! {
+        {props.children}
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TablistProps {
+    #[props(optional, default = "dxa-tablist".into())]
+    class: String,
+
+    /// The accessible label of the tab list.
+    label: String,
+
+    /// Event that is fired when the tab list is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// The container of the [`Tab`]s within [`Tabs`].
+#[component]
+pub fn Tablist(props: TablistProps) -> Element {
+    rsx! {
+        div {
+            class: "{props.class}",
+            role: "tablist",
+            aria_label: "{props.label}",
+            onmounted: move |data| props.on_mounted.call(data),
+            "data-part": "list",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TabProps {
+    #[props(optional, default = "dxa-tab".into())]
+    class: String,
+
+    /// The index of this tab, matching the index of its [`Tabpanel`].
+    index: usize,
+
+    /// Event that is fired when the tab button is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// A single tab within a [`Tablist`].
+///
+/// Only the selected tab is in the tab sequence. The other tabs are reached with the
+/// arrow keys, Home, and End.
+///
+/// A tab focused through the keyboard is marked with `data-focus-visible`.
+#[component]
+pub fn Tab(props: TabProps) -> Element {
+    let mut ctx = use_context::<TabsContext>();
+    let index = props.index;
+
+    use_drop(move || {
+        ctx.tabs.write().remove(&index);
+    });
+
+    let is_selected = (ctx.selected)() == index;
+    let mut focus_visible = use_focus_visible();
+
+    let on_key_down = move |evt: KeyboardEvent| {
+        let tabs = ctx.tabs.read();
+        let target = match evt.key() {
+            Key::ArrowRight => tabs.range(index + 1..).next().or(tabs.iter().next()),
+            Key::ArrowLeft => tabs.range(..index).next_back().or(tabs.iter().next_back()),
+            Key::Home => tabs.iter().next(),
+            Key::End => tabs.iter().next_back(),
+            _ => None,
+        };
+
+        let Some((&target, mounted)) = target else {
+            return;
+        };
+        let mounted = mounted.clone();
+        drop(tabs);
+
+        ctx.on_select.peek().call(target);
+        spawn(async move {
+            _ = mounted.set_focus(true).await;
+        });
+    };
+
+    let selected_val = match is_selected {
+        true => "true",
+        false => "false",
+    };
+
+    let tab_index_val = match is_selected {
+        true => "0",
+        false => "-1",
+    };
+
+    rsx! {
+        button {
+            class: "{props.class}",
+            id: "{ctx.tab_id(index)}",
+            role: "tab",
+            tabindex: tab_index_val,
+            // Events
+            onclick: move |_| ctx.on_select.peek().call(index),
+            onkeydown: on_key_down,
+            onfocus: move |_| focus_visible.focus(),
+            onblur: move |_| focus_visible.blur(),
+            onmounted: move |evt: MountedEvent| {
+                ctx.tabs.write().insert(index, evt.data());
+                props.on_mounted.call(evt);
+            },
+            // Aria
+            aria_selected: selected_val,
+            aria_controls: "{ctx.panel_id(index)}",
+
+            "data-focus-visible": focus_visible.attr(),
+            "data-part": "trigger",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TabpanelProps {
+    #[props(optional, default = "dxa-tabpanel".into())]
+    class: String,
+
+    /// The index of the [`Tab`] that controls this panel.
+    index: usize,
+
+    /// Event that is fired when the panel is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+/// The content associated with the [`Tab`] of the same index.
+///
+/// Panels of unselected tabs stay mounted with `hidden` so `aria-controls` always resolves.
+///
+/// `data-motion` is `from-start` or `from-end` on the panel being selected and `to-start` or
+/// `to-end` on the panel being deselected. The deselected panel is `inert` until its
+/// animations finish, and then hidden.
+#[component]
+pub fn Tabpanel(props: TabpanelProps) -> Element {
+    let ctx = use_context::<TabsContext>();
+    let is_selected = (ctx.selected)() == props.index;
+    let is_leaving = !is_selected && (ctx.previous)() == Some(props.index);
+    let motion_val = ctx.motion(props.index);
+
+    let inert_val = match is_leaving {
+        true => Some("true"),
+        false => None,
+    };
+    let index = props.index;
+    let mut find_task = use_signal(|| None::<Task>);
+
+    rsx! {
+        div {
+            class: "{props.class}",
+            id: "{ctx.panel_id(props.index)}",
+            role: "tabpanel",
+            tabindex: "0",
+            hidden: !is_selected && !is_leaving,
+            "inert": inert_val,
+            aria_labelledby: "{ctx.tab_id(props.index)}",
+
+            "data-motion": motion_val,
+            onmounted: move |data| {
+                if let Some(task) = find_task.write().take() {
+                    remove_future(task);
+                }
+                if *ctx.reveal_on_find.peek() {
+                    let task = spawn(hide_until_found(ctx.panel_id(index), move || {
+                        ctx.on_select.peek().call(index);
+                    }));
+                    find_task.set(Some(task));
+                }
+                props.on_mounted.call(data);
+            },
+
+            "data-part": "content",
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
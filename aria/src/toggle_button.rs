@@ -0,0 +1,84 @@
+use dioxus::prelude::*;
+
+use crate::{input_modality, use_aria_id, use_track_input_modality, ButtonContent, ToggleChange};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToggleButtonProps {
+    #[props(optional, default = "dxa-toggle-button".into())]
+    class: String,
+
+    /// The label and/or icon displayed in this button.
+    #[props(into)]
+    content: ButtonContent,
+
+    /// Whether the button is initially pressed.
+    /// Defaults to `false`.
+    #[props(optional, default = false)]
+    default_pressed: bool,
+
+    /// Event that is fired whenever the pressed state of the button changes.
+    #[props(optional)]
+    on_toggled: EventHandler<bool>,
+
+    /// Event that is fired alongside `on_toggled`, with the previous state and the kind of
+    /// input that caused the change.
+    #[props(optional)]
+    on_change: EventHandler<ToggleChange>,
+
+    /// Event that is fired when the button is mounted.
+    #[props(optional)]
+    on_mounted: EventHandler<MountedEvent>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// The toggle variant of the `Button` ARIA pattern.
+///
+/// The pressed state is exposed through `aria-pressed`. A visible label is associated with
+/// the button through `aria-labelledby`, while icon-only buttons use their `aria_label`.
+///
+/// See the [button pattern](https://www.w3.org/WAI/ARIA/apg/patterns/button/).
+#[component]
+pub fn ToggleButton(props: ToggleButtonProps) -> Element {
+    let label_id = use_aria_id();
+    let mut is_pressed = use_signal(|| props.default_pressed);
+    use_track_input_modality();
+
+    let on_click = move |_| {
+        let previous = is_pressed();
+        is_pressed.set(!previous);
+
+        props.on_toggled.call(!previous);
+        props.on_change.call(ToggleChange {
+            value: !previous,
+            previous,
+            source: input_modality(),
+        });
+    };
+
+    let aria_labelledby_val = props.content.visible_label().map(|_| label_id.clone());
+
+    let toggled_val = match is_pressed() {
+        true => "true",
+        false => "false",
+    };
+
+    rsx! {
+        button {
+            class: "{props.class}",
+            // Events
+            onclick: on_click,
+            onmounted: move |data| props.on_mounted.call(data),
+            // Aria
+            aria_pressed: toggled_val,
+            aria_label: props.content.aria_label(),
+            aria_labelledby: aria_labelledby_val,
+
+            "toggled": toggled_val,
+            "data-part": "root",
+            ..props.attributes,
+            {props.content.render(&label_id)}
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use dioxus::prelude::*;
+use dioxus_aria::*;
+
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+/// Assert that every element in `html` has a `data-part` attribute.
+fn assert_parts(html: &str) {
+    assert!(html.contains('<'), "nothing was rendered");
+
+    let tags = html
+        .split('<')
+        .skip(1)
+        .filter(|tag| !tag.starts_with('/') && !tag.starts_with('!'));
+
+    for tag in tags {
+        let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+        assert!(tag.contains("data-part="), "missing data-part: <{tag}>");
+    }
+}
+
+#[test]
+fn accordion_parts() {
+    assert_parts(&render(|| {
+        let expanded = use_signal(|| true);
+        rsx! {
+            Accordion {
+                label: "Details",
+                expanded,
+                indicator: rsx! { "+" },
+                "Content"
+            }
+        }
+    }));
+}
+
+#[test]
+fn alert_parts() {
+    assert_parts(&render(|| {
+        rsx! {
+            Alert {
+                variant: AlertVariant::Info,
+                AlertTitle { "Saved" }
+                AlertDescription { "Your changes have been saved." }
+                AlertDismiss { "×" }
+            }
+        }
+    }));
+}
+
+#[test]
+fn button_parts() {
+    assert_parts(&render(|| {
+        rsx! {
+            Button { label: "Save" }
+            Button {
+                label: "Mute",
+                icon: Icon { src: "mute.png".into(), width: 24, height: 24 },
+            }
+        }
+    }));
+}
+
+#[test]
+fn toggle_button_and_switch_parts() {
+    assert_parts(&render(|| {
+        let icon = Icon {
+            src: "mute.png".into(),
+            width: 24,
+            height: 24,
+        };
+        rsx! {
+            ToggleButton {
+                content: ButtonContent::IconLabel { icon: icon.clone(), label: "Mute".into() },
+            }
+            Switch {
+                content: ButtonContent::IconLabel { icon, label: "Notifications".into() },
+            }
+        }
+    }));
+}
+
+#[test]
+fn tabs_parts() {
+    assert_parts(&render(|| {
+        rsx! {
+            Tabs {
+                selected: 0,
+                Tablist {
+                    label: "Settings",
+                    Tab { index: 0, "General" }
+                    Tab { index: 1, "Privacy" }
+                }
+                Tabpanel { index: 0, "General settings" }
+                Tabpanel { index: 1, "Privacy settings" }
+            }
+        }
+    }));
+}
+
+#[test]
+fn empty_state_parts() {
+    assert_parts(&render(|| {
+        rsx! {
+            EmptyState {
+                EmptyStateIcon { "∅" }
+                EmptyStateTitle { "No projects" }
+                EmptyStateDescription { "Create a project to get started." }
+                EmptyStateActions {
+                    Button { label: "Create project" }
+                }
+            }
+            ErrorFallback {
+                error: "Failed to load projects",
+                on_retry: move |_| {},
+            }
+        }
+    }));
+}
+
+#[test]
+fn motion_provider_parts() {
+    assert_parts(&render(|| {
+        rsx! {
+            MotionProvider { "Content" }
+        }
+    }));
+}
+
+#[test]
+fn listbox_parts() {
+    assert_parts(&render(|| {
+        rsx! {
+            Listbox {
+                label: "Environments",
+                value: vec!["staging".to_string()],
+                ListboxItem { value: "development", "Development" }
+                ListboxItem { value: "staging", "Staging" }
+            }
+        }
+    }));
+}
+
+/// Find the opening tag of the element with `data-part="{part}"`.
+fn find_part<'a>(html: &'a str, part: &str) -> &'a str {
+    let marker = format!("data-part=\"{part}\"");
+    let end = html.find(&marker).expect("part is rendered");
+    let start = html[..end].rfind('<').unwrap();
+    let end = end + html[end..].find('>').unwrap();
+    &html[start..=end]
+}
+
+#[test]
+fn accordion_trigger_open_state() {
+    let html = render(|| {
+        let expanded = use_signal(|| true);
+        rsx! {
+            Accordion { label: "Details", expanded, "Content" }
+        }
+    });
+
+    let trigger = find_part(&html, "trigger");
+    assert!(trigger.contains("data-state=\"open\""), "{trigger}");
+    assert!(trigger.contains("aria-expanded=\"true\""), "{trigger}");
+}
+
+#[test]
+fn accordion_trigger_closed_state() {
+    let html = render(|| {
+        let expanded = use_signal(|| false);
+        rsx! {
+            Accordion { label: "Details", expanded, "Content" }
+        }
+    });
+
+    let trigger = find_part(&html, "trigger");
+    assert!(trigger.contains("data-state=\"closed\""), "{trigger}");
+    assert!(trigger.contains("aria-expanded=\"false\""), "{trigger}");
+}
@@ -1,5 +1,8 @@
 use dioxus::prelude::*;
-use dioxus_aria::{Button, Icon};
+use dioxus_aria::{
+    Alert, AlertDescription, AlertDismiss, AlertTitle, AlertVariant, Button, ButtonContent, Icon,
+    Listbox, ListboxItem, SelectionMode, Switch, Tab, Tablist, Tabpanel, Tabs, ToggleButton,
+};
 
 fn main() {
     launch(App);
@@ -8,6 +11,9 @@ fn main() {
 #[component]
 fn App() -> Element {
     let mut muted = use_signal(|| false);
+    let mut show_alert = use_signal(|| true);
+    let mut selected_tab = use_signal(|| 0);
+    let mut environments = use_signal(|| vec!["staging".to_string()]);
 
     let icon_src = match muted() {
         true => "https://i.fbcd.co/products/resized/resized-750-500/3cf763d411d8098d6d77892a93fb27ff802f7aed02b32ce350653463097d3c3b.jpg",
@@ -21,6 +27,16 @@ fn App() -> Element {
     };
 
     rsx! {
+        if show_alert() {
+            Alert {
+                variant: AlertVariant::Success,
+                on_dismiss: move |_| show_alert.set(false),
+                AlertTitle { "Saved" }
+                AlertDescription { "Your changes have been saved." }
+                AlertDismiss { "×" }
+            }
+        }
+
         Button {
             label: "Save",
         }
@@ -32,8 +48,41 @@ fn App() -> Element {
 
         Button {
             label: "Mute",
-            icon,
+            icon: icon.clone(),
             on_toggled: move |val| muted.set(val),
         }
+
+        ToggleButton {
+            content: ButtonContent::Icon { icon, aria_label: "Mute".into() },
+            on_toggled: move |val| muted.set(val),
+        }
+
+        Switch {
+            content: "Notifications",
+        }
+
+        Tabs {
+            selected: selected_tab(),
+            on_select: move |index| selected_tab.set(index),
+
+            Tablist {
+                label: "Settings",
+                Tab { index: 0, "General" }
+                Tab { index: 1, "Privacy" }
+            }
+            Tabpanel { index: 0, "General settings" }
+            Tabpanel { index: 1, "Privacy settings" }
+        }
+
+        Listbox {
+            label: "Environments",
+            selection_mode: SelectionMode::Multiple,
+            value: environments(),
+            on_value_change: move |value| environments.set(value),
+
+            ListboxItem { value: "development", "Development" }
+            ListboxItem { value: "staging", "Staging" }
+            ListboxItem { value: "production", disabled: true, "Production" }
+        }
     }
 }